@@ -0,0 +1,133 @@
+//! Byte-swapped memory accessors for the N64's RDRAM.
+//!
+//! The recompiler's RDRAM buffer keeps each 32-bit lane in the host's native
+//! byte order (so word-sized loads/stores are a plain pointer access), but
+//! sub-word accesses need to land on the right byte/halfword within that
+//! lane. This module centralizes the `^3`/`^2` swizzle so binding authors
+//! don't have to hand-roll it the way [`mem_b`] originally required.
+
+/// KSEG0/1-to-RDRAM-offset translation constant, matching `RecompContext::to_ptr`.
+const KSEG_OFFSET: u64 = 0xFFFFFFFF80000000;
+
+#[inline(always)]
+fn rdram_offset(addr: u64) -> usize {
+    addr.wrapping_sub(KSEG_OFFSET) as usize
+}
+
+/// Read a byte from memory using the appropriate byte swapping pattern.
+/// This is equivalent to the MEM_B macro in C++.
+///
+/// # Safety
+/// This function is unsafe because it accesses raw memory.
+#[inline]
+pub unsafe fn mem_b(rdram: *mut u8, addr: u64, offset: usize) -> i8 {
+    let byte_addr = addr.wrapping_add(offset as u64);
+    *rdram.add(rdram_offset(byte_addr ^ 3)) as i8
+}
+
+/// Read a halfword from memory using the appropriate byte swapping pattern.
+/// This is equivalent to the MEM_H macro in C++.
+///
+/// # Safety
+/// This function is unsafe because it accesses raw memory.
+#[inline]
+pub unsafe fn mem_h(rdram: *mut u8, addr: u64, offset: usize) -> i16 {
+    let half_addr = addr.wrapping_add(offset as u64);
+    (rdram.add(rdram_offset(half_addr ^ 2)) as *mut i16).read_unaligned()
+}
+
+/// Read a word from memory. This is equivalent to the MEM_W macro in C++.
+///
+/// Unlike [`mem_b`]/[`mem_h`], a word access covers a whole native-order lane
+/// so the address needs no swizzling.
+///
+/// # Safety
+/// This function is unsafe because it accesses raw memory.
+#[inline]
+pub unsafe fn mem_w(rdram: *mut u8, addr: u64, offset: usize) -> i32 {
+    let word_addr = addr.wrapping_add(offset as u64);
+    (rdram.add(rdram_offset(word_addr)) as *mut i32).read_unaligned()
+}
+
+/// Read a doubleword from memory. This is equivalent to the MEM_D macro in C++.
+///
+/// A doubleword is assembled from its two word-sized lanes in big-endian
+/// order, matching how `mem_w` itself honors the N64's big-endian memory
+/// model: the lane at `addr` holds the high 32 bits and the lane at
+/// `addr + 4` holds the low 32 bits.
+///
+/// # Safety
+/// This function is unsafe because it accesses raw memory.
+#[inline]
+pub unsafe fn mem_d(rdram: *mut u8, addr: u64, offset: usize) -> i64 {
+    let base = addr.wrapping_add(offset as u64);
+    let hi = mem_w(rdram, base, 0) as u32;
+    let lo = mem_w(rdram, base, 4) as u32;
+    ((hi as i64) << 32) | lo as i64
+}
+
+/// Write a byte to memory using the appropriate byte swapping pattern.
+///
+/// # Safety
+/// This function is unsafe because it accesses raw memory.
+#[inline]
+pub unsafe fn write_b(rdram: *mut u8, addr: u64, offset: usize, val: u8) {
+    let byte_addr = addr.wrapping_add(offset as u64);
+    *rdram.add(rdram_offset(byte_addr ^ 3)) = val;
+}
+
+/// Write a halfword to memory using the appropriate byte swapping pattern.
+///
+/// # Safety
+/// This function is unsafe because it accesses raw memory.
+#[inline]
+pub unsafe fn write_h(rdram: *mut u8, addr: u64, offset: usize, val: u16) {
+    let half_addr = addr.wrapping_add(offset as u64);
+    (rdram.add(rdram_offset(half_addr ^ 2)) as *mut i16).write_unaligned(val as i16);
+}
+
+/// Write a word to memory.
+///
+/// # Safety
+/// This function is unsafe because it accesses raw memory.
+#[inline]
+pub unsafe fn write_w(rdram: *mut u8, addr: u64, offset: usize, val: u32) {
+    let word_addr = addr.wrapping_add(offset as u64);
+    (rdram.add(rdram_offset(word_addr)) as *mut i32).write_unaligned(val as i32);
+}
+
+/// Write a doubleword to memory, as two word-sized lanes (see [`mem_d`]).
+///
+/// # Safety
+/// This function is unsafe because it accesses raw memory.
+#[inline]
+pub unsafe fn write_d(rdram: *mut u8, addr: u64, offset: usize, val: u64) {
+    let base = addr.wrapping_add(offset as u64);
+    write_w(rdram, base, 0, (val >> 32) as u32);
+    write_w(rdram, base, 4, val as u32);
+}
+
+/// Read `len` bytes starting at `addr`, reassembling them in native byte
+/// order so callers can move structs and buffers without per-byte loops.
+///
+/// # Safety
+/// This function is unsafe because it accesses raw memory.
+pub unsafe fn read_bytes(rdram: *mut u8, addr: u64, len: usize) -> Vec<u8> {
+    let mut result = Vec::with_capacity(len);
+    for i in 0..len {
+        result.push(mem_b(rdram, addr, i) as u8);
+    }
+    result
+}
+
+/// Write `bytes` to memory starting at `addr`, applying the correct swizzle
+/// for each byte so callers can move structs and buffers without per-byte
+/// loops.
+///
+/// # Safety
+/// This function is unsafe because it accesses raw memory.
+pub unsafe fn write_bytes(rdram: *mut u8, addr: u64, bytes: &[u8]) {
+    for (i, &byte) in bytes.iter().enumerate() {
+        write_b(rdram, addr, i, byte);
+    }
+}
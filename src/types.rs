@@ -46,6 +46,49 @@ pub struct FprUint {
     pub u32h: u32,
 }
 
+/// FPU rounding mode, as encoded in FCR31 bits 0-1
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RoundingMode {
+    /// Round to nearest, ties to even (RM = 0)
+    Nearest,
+    /// Round toward zero / truncate (RM = 1)
+    TowardZero,
+    /// Round toward +infinity (RM = 2)
+    Up,
+    /// Round toward -infinity (RM = 3)
+    Down,
+}
+
+/// The IEEE 754 floating-point exceptions tracked by FCR31's Enable, Cause,
+/// and Flag bit groups
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FpException {
+    /// Inexact result
+    Inexact,
+    /// Underflow
+    Underflow,
+    /// Overflow
+    Overflow,
+    /// Division by zero
+    DivideByZero,
+    /// Invalid operation
+    Invalid,
+}
+
+impl FpException {
+    /// Bit offset of this exception within each FCR31 bit group (Flags,
+    /// Enables, Cause all use the same I/U/O/Z/V ordering)
+    fn bit_offset(self) -> u32 {
+        match self {
+            FpException::Inexact => 0,
+            FpException::Underflow => 1,
+            FpException::Overflow => 2,
+            FpException::DivideByZero => 3,
+            FpException::Invalid => 4,
+        }
+    }
+}
+
 /// Complete state of a MIPS64 CPU for recompilation
 ///
 /// This structure contains all CPU registers and states needed for
@@ -169,8 +212,15 @@ pub struct RecompContext {
 
     /// Flag indicating MIPS3 floating-point mode
     pub mips3_float_mode: u8,
+
+    /// FPU control/status register (FCR31): rounding mode, condition codes,
+    /// and exception enable/cause/flag bits
+    pub fcr31: u32,
 }
 
+/// Mask of the FR (floating-point register mode) bit, bit 26, in the status register
+const STATUS_FR_BIT: u32 = 1 << 26;
+
 // Useful register aliases
 impl RecompContext {
     // GPR aliases according to MIPS convention
@@ -327,3 +377,305 @@ impl RecompContext {
         self.r31
     }
 }
+
+// FR-mode-aware FPU register file access
+impl RecompContext {
+    /// Get a reference to FPU register `n` (0-31)
+    pub(crate) fn fpr(&self, n: usize) -> &Fpr {
+        match n {
+            0 => &self.f0,
+            1 => &self.f1,
+            2 => &self.f2,
+            3 => &self.f3,
+            4 => &self.f4,
+            5 => &self.f5,
+            6 => &self.f6,
+            7 => &self.f7,
+            8 => &self.f8,
+            9 => &self.f9,
+            10 => &self.f10,
+            11 => &self.f11,
+            12 => &self.f12,
+            13 => &self.f13,
+            14 => &self.f14,
+            15 => &self.f15,
+            16 => &self.f16,
+            17 => &self.f17,
+            18 => &self.f18,
+            19 => &self.f19,
+            20 => &self.f20,
+            21 => &self.f21,
+            22 => &self.f22,
+            23 => &self.f23,
+            24 => &self.f24,
+            25 => &self.f25,
+            26 => &self.f26,
+            27 => &self.f27,
+            28 => &self.f28,
+            29 => &self.f29,
+            30 => &self.f30,
+            31 => &self.f31,
+            _ => panic!("Invalid FPU register index: {}", n),
+        }
+    }
+
+    /// Get a mutable reference to FPU register `n` (0-31)
+    pub(crate) fn fpr_mut(&mut self, n: usize) -> &mut Fpr {
+        match n {
+            0 => &mut self.f0,
+            1 => &mut self.f1,
+            2 => &mut self.f2,
+            3 => &mut self.f3,
+            4 => &mut self.f4,
+            5 => &mut self.f5,
+            6 => &mut self.f6,
+            7 => &mut self.f7,
+            8 => &mut self.f8,
+            9 => &mut self.f9,
+            10 => &mut self.f10,
+            11 => &mut self.f11,
+            12 => &mut self.f12,
+            13 => &mut self.f13,
+            14 => &mut self.f14,
+            15 => &mut self.f15,
+            16 => &mut self.f16,
+            17 => &mut self.f17,
+            18 => &mut self.f18,
+            19 => &mut self.f19,
+            20 => &mut self.f20,
+            21 => &mut self.f21,
+            22 => &mut self.f22,
+            23 => &mut self.f23,
+            24 => &mut self.f24,
+            25 => &mut self.f25,
+            26 => &mut self.f26,
+            27 => &mut self.f27,
+            28 => &mut self.f28,
+            29 => &mut self.f29,
+            30 => &mut self.f30,
+            31 => &mut self.f31,
+            _ => panic!("Invalid FPU register index: {}", n),
+        }
+    }
+
+    /// Returns true when the FPU is in 64-bit register mode (status register FR=1),
+    /// where all 32 floating-point registers are independently addressable as
+    /// either singles or doubles. A nonzero `mips3_float_mode` selects FR=1 mode
+    /// regardless of the status register bit; when it is zero, the status
+    /// register's FR bit decides.
+    #[inline(always)]
+    fn fr_mode(&self) -> bool {
+        self.mips3_float_mode != 0 || (self.status_reg & STATUS_FR_BIT) != 0
+    }
+
+    /// Read FPU register `n` as a single-precision float, honoring the FR mode.
+    ///
+    /// In FR=1 mode every register is independent and this reads the low 32 bits
+    /// of `f{n}`. In FR=0 mode only even registers physically exist as doubles:
+    /// even `n` reads the low half of `f{n}.d`, and odd `n` reads the high half
+    /// of the preceding even register through `f_odd`, which addresses the high
+    /// half of `f0` (register `n` is `(n - 1) * 2` words past it).
+    ///
+    /// # Safety
+    /// This function is unsafe because it reads the union's float field and,
+    /// in FR=0 mode for odd `n`, dereferences the raw `f_odd` pointer, which
+    /// must point at the high half of `f0`.
+    pub unsafe fn read_fgr_s(&self, n: usize) -> f32 {
+        if self.fr_mode() || n.is_multiple_of(2) {
+            self.fpr(n).f.fl
+        } else {
+            f32::from_bits(*self.f_odd.add((n - 1) * 2))
+        }
+    }
+
+    /// Write FPU register `n` as a single-precision float, honoring the FR mode.
+    ///
+    /// See [`Self::read_fgr_s`] for how `n` is resolved in each mode.
+    ///
+    /// # Safety
+    /// This function is unsafe because it writes the union's float field and,
+    /// in FR=0 mode for odd `n`, dereferences the raw `f_odd` pointer, which
+    /// must point at the high half of `f0`.
+    pub unsafe fn write_fgr_s(&mut self, n: usize, val: f32) {
+        if self.fr_mode() || n.is_multiple_of(2) {
+            self.fpr_mut(n).f.fl = val;
+        } else {
+            *self.f_odd.add((n - 1) * 2) = val.to_bits();
+        }
+    }
+
+    /// Read FPU register `n` as a double-precision float, honoring the FR mode.
+    ///
+    /// In FR=1 mode every register is independent and this reads `f{n}.d`
+    /// directly. In FR=0 mode only even-numbered registers physically exist
+    /// as doubles; `n` must be even.
+    ///
+    /// # Safety
+    /// This function is unsafe because it reads the union's double field.
+    pub unsafe fn read_fgr_d(&self, n: usize) -> f64 {
+        if !self.fr_mode() {
+            assert!(n.is_multiple_of(2), "Odd-numbered double FPU access ({}) is illegal in FR=0 mode", n);
+        }
+        self.fpr(n).d
+    }
+
+    /// Write FPU register `n` as a double-precision float, honoring the FR mode.
+    ///
+    /// See [`Self::read_fgr_d`] for how `n` is resolved in each mode.
+    ///
+    /// # Safety
+    /// This function is unsafe because it writes the union's double field.
+    pub unsafe fn write_fgr_d(&mut self, n: usize, val: f64) {
+        if !self.fr_mode() {
+            assert!(n.is_multiple_of(2), "Odd-numbered double FPU access ({}) is illegal in FR=0 mode", n);
+        }
+        self.fpr_mut(n).d = val;
+    }
+}
+
+// FCR31 (FPU control/status register) access
+impl RecompContext {
+    /// FP condition-code bit used by branch-on-FP-condition (FCR31 bit 23,
+    /// aka CC0). Equivalent to `cc(0)`.
+    #[inline(always)]
+    pub fn fp_cond(&self) -> bool {
+        self.cc(0)
+    }
+
+    /// Set the FP condition-code bit (FCR31 bit 23, aka CC0). Equivalent to
+    /// `set_cc(0, val)`.
+    #[inline(always)]
+    pub fn set_fp_cond(&mut self, val: bool) {
+        self.set_cc(0, val);
+    }
+
+    /// Read extended condition code `cc` (0-7). CC0 is FCR31 bit 23; CC1-CC7
+    /// are bits 25-31.
+    pub fn cc(&self, cc: u32) -> bool {
+        (self.fcr31 & (1 << Self::cc_bit(cc))) != 0
+    }
+
+    /// Set extended condition code `cc` (0-7). See [`Self::cc`].
+    pub fn set_cc(&mut self, cc: u32, val: bool) {
+        let bit = Self::cc_bit(cc);
+        if val {
+            self.fcr31 |= 1 << bit;
+        } else {
+            self.fcr31 &= !(1 << bit);
+        }
+    }
+
+    /// FCR31 bit position of condition code `cc` (0-7)
+    fn cc_bit(cc: u32) -> u32 {
+        assert!(cc <= 7, "FP condition code out of range: {}", cc);
+        if cc == 0 {
+            23
+        } else {
+            24 + cc
+        }
+    }
+
+    /// Current rounding mode (FCR31 bits 0-1)
+    pub fn rounding_mode(&self) -> RoundingMode {
+        match self.fcr31 & 0b11 {
+            0 => RoundingMode::Nearest,
+            1 => RoundingMode::TowardZero,
+            2 => RoundingMode::Up,
+            3 => RoundingMode::Down,
+            _ => unreachable!(),
+        }
+    }
+
+    /// Set the rounding mode (FCR31 bits 0-1)
+    pub fn set_rounding_mode(&mut self, mode: RoundingMode) {
+        let bits = match mode {
+            RoundingMode::Nearest => 0,
+            RoundingMode::TowardZero => 1,
+            RoundingMode::Up => 2,
+            RoundingMode::Down => 3,
+        };
+        self.fcr31 = (self.fcr31 & !0b11) | bits;
+    }
+
+    /// Round `val` to an integral value using the FPU's current rounding
+    /// mode. Used by [`Self::cvt_w_fmt`]/[`Self::cvt_l_fmt`] rather than the
+    /// host's default IEEE rounding, so their results agree with the
+    /// guest-configured mode.
+    pub fn round_with_mode(&self, val: f64) -> f64 {
+        match self.rounding_mode() {
+            RoundingMode::Nearest => val.round_ties_even(),
+            RoundingMode::TowardZero => val.trunc(),
+            RoundingMode::Up => val.ceil(),
+            RoundingMode::Down => val.floor(),
+        }
+    }
+
+    /// Convert `val` to a 32-bit integer using the current rounding mode and
+    /// write the bit pattern into FPU register `n`, as CVT.W.S/CVT.W.D do.
+    ///
+    /// # Safety
+    /// This function is unsafe because it writes the union's integer field.
+    pub unsafe fn cvt_w_fmt(&mut self, n: usize, val: f64) {
+        let result = self.round_with_mode(val) as i32;
+        self.fpr_mut(n).u.u32l = result as u32;
+    }
+
+    /// Convert `val` to a 64-bit integer using the current rounding mode and
+    /// write the bit pattern into FPU register `n`, as CVT.L.S/CVT.L.D do.
+    ///
+    /// # Safety
+    /// This function is unsafe because it writes the union's integer field.
+    pub unsafe fn cvt_l_fmt(&mut self, n: usize, val: f64) {
+        let result = self.round_with_mode(val) as i64;
+        self.fpr_mut(n).u64 = result as u64;
+    }
+
+    /// Whether the FPU traps on `exc` (FCR31 Enable bits, 7-11)
+    pub fn fp_enable(&self, exc: FpException) -> bool {
+        self.fp_exception_bit(7, exc)
+    }
+
+    /// Enable or disable trapping on `exc` (FCR31 Enable bits, 7-11)
+    pub fn set_fp_enable(&mut self, exc: FpException, val: bool) {
+        self.set_fp_exception_bit(7, exc, val);
+    }
+
+    /// Whether `exc` was the cause of the most recent FP exception (FCR31
+    /// Cause bits, 12-16)
+    pub fn fp_cause(&self, exc: FpException) -> bool {
+        self.fp_exception_bit(12, exc)
+    }
+
+    /// Set the cause bit for `exc` (FCR31 Cause bits, 12-16)
+    pub fn set_fp_cause(&mut self, exc: FpException, val: bool) {
+        self.set_fp_exception_bit(12, exc, val);
+    }
+
+    /// Whether `exc` has occurred since last cleared (FCR31 Flag bits, 2-6)
+    pub fn fp_flag(&self, exc: FpException) -> bool {
+        self.fp_exception_bit(2, exc)
+    }
+
+    /// Set the sticky flag bit for `exc` (FCR31 Flag bits, 2-6)
+    pub fn set_fp_flag(&mut self, exc: FpException, val: bool) {
+        self.set_fp_exception_bit(2, exc, val);
+    }
+
+    /// Read the bit for `exc` within the 5-bit group starting at `base`
+    /// (shared by the Flags, Enables, and Cause groups, which all use the
+    /// same I/U/O/Z/V ordering)
+    fn fp_exception_bit(&self, base: u32, exc: FpException) -> bool {
+        (self.fcr31 & (1 << (base + exc.bit_offset()))) != 0
+    }
+
+    /// Set the bit for `exc` within the 5-bit group starting at `base`. See
+    /// [`Self::fp_exception_bit`].
+    fn set_fp_exception_bit(&mut self, base: u32, exc: FpException, val: bool) {
+        let bit = base + exc.bit_offset();
+        if val {
+            self.fcr31 |= 1 << bit;
+        } else {
+            self.fcr31 &= !(1 << bit);
+        }
+    }
+}
@@ -1,17 +1,20 @@
+use crate::memory::{mem_b, mem_d, mem_w};
 use crate::types::RecompContext;
 
-/// Read a byte from memory using the appropriate byte swapping pattern.
-/// This is equivalent to the MEM_B macro in C++.
-///
-/// # Safety
-/// This function is unsafe because it accesses raw memory.
-#[inline]
-pub unsafe fn mem_b(rdram: *mut u8, addr: u64, offset: usize) -> i8 {
-    let byte_addr = addr.wrapping_add(offset as u64);
-    let rdram_offset = (byte_addr ^ 3).wrapping_sub(0xFFFFFFFF80000000);
-    *rdram.add(rdram_offset as usize) as i8
+/// Calling convention used to resolve argument registers beyond the raw a0-a3 slots.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Abi {
+    /// O32: four integer argument registers (a0-a3), two float registers (f12, f14)
+    O32,
+    /// N64: eight integer argument registers (a0-a7), eight float registers (f12-f19)
+    N64,
 }
 
+/// Size in bytes of a stack-spilled argument slot. The recompiler context keeps
+/// every GPR as a 64-bit `Gpr`, so spilled arguments are read as 64-bit slots
+/// regardless of the original 32-bit O32 stack layout.
+const ARG_STACK_REGSIZE: u64 = 8;
+
 impl RecompContext {
     /// Converts a virtual address to a pointer in the RDRAM
     ///
@@ -97,10 +100,109 @@ impl RecompContext {
         result
     }
 
-    /// Set the return value in the appropriate register based on type
+    /// Number of integer argument registers (a0-a3 or a0-a7) available for `abi`
+    /// before arguments spill to the stack.
+    #[inline]
+    fn abi_int_regs(abi: Abi) -> usize {
+        match abi {
+            Abi::O32 => 4,
+            Abi::N64 => 8,
+        }
+    }
+
+    /// Get a u64 argument honoring the calling convention `abi`. Indices within
+    /// the ABI's integer register count are read from a0-a3 (O32) or a0-a7
+    /// (N64); higher indices are read from the caller's stack frame at
+    /// `sp + index * regsize` with the correct byte-swapped memory load.
+    ///
+    /// # Safety
+    /// This function is unsafe because, for stack-spilled arguments, it
+    /// accesses raw memory through `rdram`.
+    pub unsafe fn get_arg_u64_abi(&self, rdram: *mut u8, index: usize, abi: Abi) -> u64 {
+        if index < Self::abi_int_regs(abi) {
+            match index {
+                0 => self.a0(),
+                1 => self.a1(),
+                2 => self.a2(),
+                3 => self.a3(),
+                // N64 a4-a7 are the same physical registers (r8-r11) as
+                // O32's t0-t3, not r12-r15 (t4-t7).
+                4 => self.t0(),
+                5 => self.t1(),
+                6 => self.t2(),
+                7 => self.t3(),
+                _ => unreachable!(),
+            }
+        } else {
+            let addr = self.sp().wrapping_add(index as u64 * ARG_STACK_REGSIZE);
+            mem_d(rdram, addr, 0) as u64
+        }
+    }
+
+    /// Get a u32 argument honoring the calling convention `abi`. See
+    /// [`Self::get_arg_u64_abi`] for register/stack resolution.
+    ///
+    /// # Safety
+    /// This function is unsafe because, for stack-spilled arguments, it
+    /// accesses raw memory through `rdram`.
+    #[inline]
+    pub unsafe fn get_arg_u32_abi(&self, rdram: *mut u8, index: usize, abi: Abi) -> u32 {
+        self.get_arg_u64_abi(rdram, index, abi) as u32
+    }
+
+    /// Get a pointer argument honoring the calling convention `abi`, converting
+    /// the virtual address to an RDRAM offset.
+    ///
+    /// # Safety
+    /// This function is unsafe because it returns a raw pointer which must be
+    /// properly aligned and within bounds of the RDRAM.
+    #[inline]
+    pub unsafe fn get_arg_ptr_abi<T>(&self, rdram: *mut u8, index: usize, abi: Abi) -> *mut T {
+        let addr = self.get_arg_u64_abi(rdram, index, abi);
+        self.to_ptr(rdram, addr)
+    }
+
+    /// Get an f32 argument honoring the calling convention `abi`. O32 only has
+    /// two float argument registers (f12 for index 0, f14 for index 1); N64
+    /// has eight (f12-f19, one per index). Any other index is read from the
+    /// stack slot at `sp + index * regsize`; for O32 this includes indices 2
+    /// and 3, which an integer argument would take from a2/a3.
     ///
-    /// # Type Parameters
-    /// * `T`: The type of value to return (must be 32-bit or smaller)
+    /// # Safety
+    /// This function is unsafe because, for stack-spilled arguments, it
+    /// accesses raw memory through `rdram`.
+    pub unsafe fn get_arg_f32_abi(&self, rdram: *mut u8, index: usize, abi: Abi) -> f32 {
+        match (abi, index) {
+            (Abi::O32, 0) => self.f12.f.fl,
+            (Abi::O32, 1) => self.f14.f.fl,
+            (Abi::N64, i) if i < 8 => self.fpr(12 + i).f.fl,
+            _ => {
+                let addr = self.sp().wrapping_add(index as u64 * ARG_STACK_REGSIZE);
+                f32::from_bits(mem_w(rdram, addr, 0) as u32)
+            }
+        }
+    }
+
+    /// Get an f64 argument honoring the calling convention `abi`, reading the
+    /// paired single/double register. See [`Self::get_arg_f32_abi`] for how
+    /// `index` is resolved to a float register or stack slot.
+    ///
+    /// # Safety
+    /// This function is unsafe because, for stack-spilled arguments, it
+    /// accesses raw memory through `rdram`.
+    pub unsafe fn get_arg_f64_abi(&self, rdram: *mut u8, index: usize, abi: Abi) -> f64 {
+        match (abi, index) {
+            (Abi::O32, 0) => self.f12.d,
+            (Abi::O32, 1) => self.f14.d,
+            (Abi::N64, i) if i < 8 => self.fpr(12 + i).d,
+            _ => {
+                let addr = self.sp().wrapping_add(index as u64 * ARG_STACK_REGSIZE);
+                f64::from_bits(mem_d(rdram, addr, 0) as u64)
+            }
+        }
+    }
+
+    /// Set the return value in the appropriate register based on type.
     ///
     /// # Arguments
     /// * `val`: The value to return
@@ -110,31 +212,143 @@ impl RecompContext {
     /// ctx.set_return(1); // Set integer return value
     /// ctx.set_return(3.14f32); // Set float return value
     /// ```
-    pub fn set_return<T>(&mut self, val: T)
-    where
-        T: Copy,
-    {
-        // Use a compile-time check for type and size (Rust equivalent of static_assert)
-        let type_name = std::any::type_name::<T>();
-
-        if type_name == "f32" {
-            // For float types, set the f0 register
-            let float_val = unsafe { std::mem::transmute_copy::<T, f32>(&val) };
-            self.f0.f.fl = float_val;
-        } else if type_name == "i32"
-            || type_name == "u32"
-            || type_name == "i16"
-            || type_name == "u16"
-            || type_name == "i8"
-            || type_name == "u8"
-            || type_name == "bool"
-        {
-            // For integer or boolean types, set the r2 register (v0)
-            // First cast to i32 and then to u64
-            let int_val = unsafe { std::mem::transmute_copy::<T, i32>(&val) };
-            self.r2 = int_val as u64;
-        } else {
-            panic!("Unsupported return type: {}", type_name);
-        }
+    pub fn set_return<T: RecompReturn>(&mut self, val: T) {
+        val.store_return(self);
+    }
+
+    /// Get an argument from registers a0-a3 (or f12, for index 0 floats/doubles),
+    /// dispatching on `T` to the right register and width.
+    ///
+    /// # Safety
+    /// This function is unsafe because [`RecompArg::get_arg`] is. The
+    /// implementations in this module only read registers and never access
+    /// `rdram`; pointer arguments come back as a [`RecompPtr`] virtual address,
+    /// not a host pointer.
+    pub unsafe fn get_arg<T: RecompArg>(&self, rdram: *mut u8, index: usize) -> T {
+        T::get_arg(self, rdram, index)
+    }
+}
+
+/// A pointer-valued return or argument, carrying a raw virtual address rather
+/// than an RDRAM-relative host pointer. Kept distinct from `u64` so
+/// `RecompReturn`/`RecompArg` can tell a pointer apart from a plain integer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RecompPtr(pub u64);
+
+/// Stores a recompiled function's return value into the correct register(s)
+/// of a [`RecompContext`], replacing the fragile `type_name` string match with
+/// a trait dispatched at compile time.
+pub trait RecompReturn {
+    /// Write `self` into the appropriate return register(s) of `ctx`.
+    fn store_return(&self, ctx: &mut RecompContext);
+}
+
+macro_rules! impl_recomp_return_narrow_int {
+    ($($ty:ty),*) => {
+        $(
+            impl RecompReturn for $ty {
+                fn store_return(&self, ctx: &mut RecompContext) {
+                    // 32-bit-or-smaller results are always sign-extended into
+                    // the 64-bit GPR, matching MIPS64 register semantics.
+                    ctx.r2 = (*self as i32) as u64;
+                }
+            }
+        )*
+    };
+}
+
+impl_recomp_return_narrow_int!(i8, u8, i16, u16, i32, u32, bool);
+
+impl RecompReturn for i64 {
+    fn store_return(&self, ctx: &mut RecompContext) {
+        ctx.r2 = *self as u64;
+    }
+}
+
+impl RecompReturn for u64 {
+    fn store_return(&self, ctx: &mut RecompContext) {
+        ctx.r2 = *self;
+    }
+}
+
+impl RecompReturn for f32 {
+    fn store_return(&self, ctx: &mut RecompContext) {
+        ctx.f0.f.fl = *self;
+    }
+}
+
+impl RecompReturn for f64 {
+    fn store_return(&self, ctx: &mut RecompContext) {
+        ctx.f0.d = *self;
+    }
+}
+
+impl RecompReturn for RecompPtr {
+    fn store_return(&self, ctx: &mut RecompContext) {
+        ctx.r2 = self.0;
+    }
+}
+
+/// Reads a recompiled function's argument out of the correct register of a
+/// [`RecompContext`], mirroring [`RecompReturn`] to power a generic
+/// [`RecompContext::get_arg`].
+pub trait RecompArg: Sized {
+    /// Read argument `index` from `ctx`/`rdram`.
+    ///
+    /// # Safety
+    /// This function is unsafe because an implementation may access raw
+    /// memory through `rdram`. The implementations in this module read only
+    /// registers and ignore `rdram`.
+    unsafe fn get_arg(ctx: &RecompContext, rdram: *mut u8, index: usize) -> Self;
+}
+
+macro_rules! impl_recomp_arg_narrow_int {
+    ($($ty:ty),*) => {
+        $(
+            impl RecompArg for $ty {
+                unsafe fn get_arg(ctx: &RecompContext, _rdram: *mut u8, index: usize) -> Self {
+                    ctx.get_arg_u32(index) as Self
+                }
+            }
+        )*
+    };
+}
+
+impl_recomp_arg_narrow_int!(i8, u8, i16, u16, i32, u32);
+
+impl RecompArg for bool {
+    unsafe fn get_arg(ctx: &RecompContext, _rdram: *mut u8, index: usize) -> Self {
+        ctx.get_arg_u32(index) != 0
+    }
+}
+
+impl RecompArg for i64 {
+    unsafe fn get_arg(ctx: &RecompContext, _rdram: *mut u8, index: usize) -> Self {
+        ctx.get_arg_u64(index) as Self
+    }
+}
+
+impl RecompArg for u64 {
+    unsafe fn get_arg(ctx: &RecompContext, _rdram: *mut u8, index: usize) -> Self {
+        ctx.get_arg_u64(index)
+    }
+}
+
+impl RecompArg for f32 {
+    unsafe fn get_arg(ctx: &RecompContext, _rdram: *mut u8, index: usize) -> Self {
+        ctx.get_arg_f32(index)
+    }
+}
+
+impl RecompArg for f64 {
+    unsafe fn get_arg(ctx: &RecompContext, _rdram: *mut u8, index: usize) -> Self {
+        assert!(index == 0, "Doubles only supported in arg 0 (f12)");
+        ctx.f12.d
+    }
+}
+
+impl RecompArg for RecompPtr {
+    unsafe fn get_arg(ctx: &RecompContext, _rdram: *mut u8, index: usize) -> Self {
+        RecompPtr(ctx.get_arg_u64(index))
     }
 }